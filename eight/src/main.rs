@@ -134,7 +134,7 @@ impl Search {
         }
     }
 
-    fn offsets_to_z(&self) -> (u128, u128) {
+    fn offsets_to_z(&self) -> (Vec<u128>, u128) {
         let iter = self
             .path
             .iter()
@@ -146,28 +146,29 @@ impl Search {
                 nums.push(n as u128);
             }
         }
-        assert_eq!(nums.len(), 1);
-        (nums[0], self.path.len() as u128)
+        (nums, self.path.len() as u128)
     }
 }
 
-fn find_turns(first: (u128, u128), mut rest: Vec<(u128, u128)>, out: &mut u128) {
-    if rest.is_empty() {
-        return;
+fn ext_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    if b == 0 {
+        (a, 1, 0)
+    } else {
+        let (g, x, y) = ext_gcd(b, a % b);
+        (g, y, x - a / b * y)
     }
-    let next = rest.remove(0);
+}
 
-    let mut advance = 0;
-    let mut t = *out % next.1;
-    while t != next.0 {
-        t = (t + first.1) % next.1;
-        advance += first.1;
+fn crt((a, n): (u128, u128), (b, m): (u128, u128)) -> Option<(u128, u128)> {
+    let (g, p, _) = ext_gcd(n as i128, m as i128);
+    let diff = b as i128 - a as i128;
+    if diff % g != 0 {
+        return None;
     }
-
-    *out += advance;
-
-    let super_wheel = (0, num::integer::lcm(first.1, next.1));
-    find_turns(super_wheel, rest, out);
+    let m_g = m as i128 / g;
+    let k = (diff / g % m_g * p % m_g).rem_euclid(m_g) as u128;
+    let lcm = n / g as u128 * m;
+    Some(((a + n * k) % lcm, lcm))
 }
 
 #[part_two]
@@ -202,20 +203,20 @@ fn part_two(input: Input) -> u128 {
             break;
         }
     }
-    let mut nums: Vec<_> = curs.iter().map(|c| c.offsets_to_z()).collect();
-    nums.sort();
-    let first = nums.remove(0);
-
-    // turn the wheels so the first wheel is at the end
-    let initial_step = first.0;
-    for n in &mut nums {
-        n.0 -= initial_step;
+    let mut alignments = vec![(0, 1)];
+    for (hits, len) in curs.iter().map(|c| c.offsets_to_z()) {
+        alignments = alignments
+            .into_iter()
+            .flat_map(|a| hits.iter().filter_map(move |&h| crt(a, (h, len))))
+            .collect();
     }
 
-    let mut v = 0;
-    find_turns((0, first.1), nums, &mut v);
-
-    num_steps + initial_step + v
+    num_steps
+        + alignments
+            .into_iter()
+            .map(|(t, _)| t)
+            .min()
+            .expect("ghost cycles never align")
 }
 
 harness!(part_1: 22199, part_2: 13334102464297);
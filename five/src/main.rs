@@ -13,38 +13,28 @@ impl RangeMap {
         let mut unmapped = vec![];
         for range in value.ranges {
             let range_end = range.start + range.len;
-            if range.start < self.src {
-                if range_end <= self.src {
-                    unmapped.push(range);
-                } else {
-                    unmapped.push(Range {
-                        start: range.start,
-                        len: self.src - range.start,
-                    });
-                    mapped.push(Range {
-                        start: self.dest,
-                        len: range_end - self.src,
-                    });
-                }
-            } else {
-                let self_end = self.src + self.len;
-                if range.start >= self_end {
-                    unmapped.push(range);
-                } else if range_end <= self_end {
-                    mapped.push(Range {
-                        start: self.dest + range.start - self.src,
-                        len: range.len,
-                    });
-                } else {
-                    mapped.push(Range {
-                        start: self.dest + range.start - self.src,
-                        len: self_end - range.start,
-                    });
-                    unmapped.push(Range {
-                        start: self_end,
-                        len: range_end - self_end,
-                    });
-                }
+            let overlap_start = range.start.max(self.src);
+            let overlap_end = range_end.min(self.src + self.len);
+            if overlap_start >= overlap_end {
+                unmapped.push(range);
+                continue;
+            }
+
+            mapped.push(Range {
+                start: self.dest + overlap_start - self.src,
+                len: overlap_end - overlap_start,
+            });
+            if range.start < overlap_start {
+                unmapped.push(Range {
+                    start: range.start,
+                    len: overlap_start - range.start,
+                });
+            }
+            if overlap_end < range_end {
+                unmapped.push(Range {
+                    start: overlap_end,
+                    len: range_end - overlap_end,
+                });
             }
         }
         assert!(!(mapped.is_empty() && unmapped.is_empty()));
@@ -144,7 +134,7 @@ impl MultiRange {
 
     fn merge(&mut self, other: Self) {
         self.ranges.extend(other.ranges);
-        self.ranges.sort()
+        *self = std::mem::take(&mut self.ranges).into();
     }
 
     fn is_empty(&self) -> bool {
@@ -155,7 +145,16 @@ impl MultiRange {
 impl From<Vec<Range>> for MultiRange {
     fn from(mut ranges: Vec<Range>) -> Self {
         ranges.sort();
-        Self { ranges }
+        let mut coalesced: Vec<Range> = vec![];
+        for range in ranges {
+            match coalesced.last_mut() {
+                Some(last) if range.start <= last.start + last.len => {
+                    last.len = last.len.max(range.start + range.len - last.start);
+                }
+                _ => coalesced.push(range),
+            }
+        }
+        Self { ranges: coalesced }
     }
 }
 
@@ -1,5 +1,6 @@
 use advent::prelude::*;
 use std::fmt;
+use std::hash::Hash;
 
 #[derive(Copy, Clone, Debug, Hash, HasParser, PartialEq, Eq)]
 enum Entry {
@@ -21,131 +22,86 @@ impl fmt::Display for Entry {
     }
 }
 
-fn move_north(grid: &mut Grid<Entry>) -> bool {
-    let height = grid.height();
-
-    let mut moved = false;
-    for r in 1..height {
-        let row = grid[r].clone();
-        for (c, _) in row
-            .into_iter()
-            .enumerate()
-            .filter(|(_, e)| *e == Entry::RoundRock)
-        {
-            if grid[r - 1][c] == Entry::Empty {
-                grid[r - 1][c] = Entry::RoundRock;
-                grid[r][c] = Entry::Empty;
-                moved = true;
-            }
-        }
-    }
-    moved
+#[derive(Copy, Clone)]
+enum Tilt {
+    North,
+    West,
+    South,
+    East,
 }
 
-fn move_south(grid: &mut Grid<Entry>) -> bool {
-    let height = grid.height();
-
-    let mut moved = false;
-    for r in (0..(height - 1)).rev() {
-        let row = grid[r].clone();
-        for (c, _) in row
-            .into_iter()
-            .enumerate()
-            .filter(|(_, e)| *e == Entry::RoundRock)
-        {
-            if grid[r + 1][c] == Entry::Empty {
-                grid[r + 1][c] = Entry::RoundRock;
-                grid[r][c] = Entry::Empty;
-                moved = true;
-            }
+impl Tilt {
+    fn apply(self, r: usize, c: usize, w: usize, h: usize) -> Option<(usize, usize)> {
+        match self {
+            Self::North => (r > 0).then(|| (r - 1, c)),
+            Self::West => (c > 0).then(|| (r, c - 1)),
+            Self::South => (r + 1 < h).then(|| (r + 1, c)),
+            Self::East => (c + 1 < w).then(|| (r, c + 1)),
         }
     }
-    moved
 }
 
-fn move_east(grid: &mut Grid<Entry>) -> bool {
+fn move_rocks(grid: &mut Grid<Entry>, tilt: Tilt) -> bool {
     let width = grid.width();
+    let height = grid.height();
 
     let mut moved = false;
-    for c in (0..(width - 1)).rev() {
-        let column: Vec<Entry> = grid.column(c).into_iter().copied().collect();
-        for (r, _) in column
-            .into_iter()
-            .enumerate()
-            .filter(|(_, e)| *e == Entry::RoundRock)
-        {
-            if grid[r][c + 1] == Entry::Empty {
-                grid[r][c + 1] = Entry::RoundRock;
-                grid[r][c] = Entry::Empty;
-                moved = true;
+    for r in 0..height {
+        for c in 0..width {
+            if grid[r][c] != Entry::RoundRock {
+                continue;
+            }
+            if let Some((new_r, new_c)) = tilt.apply(r, c, width, height) {
+                if grid[new_r][new_c] == Entry::Empty {
+                    grid[new_r][new_c] = Entry::RoundRock;
+                    grid[r][c] = Entry::Empty;
+                    moved = true;
+                }
             }
         }
     }
     moved
 }
 
-fn move_west(grid: &mut Grid<Entry>) -> bool {
-    let width = grid.width();
-
-    let mut moved = false;
-    for c in 1..width {
-        let column: Vec<Entry> = grid.column(c).into_iter().copied().collect();
-        for (r, _) in column
-            .into_iter()
-            .enumerate()
-            .filter(|(_, e)| *e == Entry::RoundRock)
-        {
-            if grid[r][c - 1] == Entry::Empty {
-                grid[r][c - 1] = Entry::RoundRock;
-                grid[r][c] = Entry::Empty;
-                moved = true;
-            }
-        }
-    }
-    moved
+fn spin_cycle(grid: &mut Grid<Entry>) {
+    while move_rocks(grid, Tilt::North) {}
+    while move_rocks(grid, Tilt::West) {}
+    while move_rocks(grid, Tilt::South) {}
+    while move_rocks(grid, Tilt::East) {}
 }
 
-#[part_one]
-fn part_one(mut grid: Grid<Entry>) -> usize {
+fn north_load(grid: &Grid<Entry>) -> usize {
     let height = grid.height();
-
-    while move_north(&mut grid) {}
-
     grid.rows()
         .enumerate()
         .map(|(i, row)| row.iter().filter(|c| **c == Entry::RoundRock).count() * (height - i))
         .sum()
 }
 
-#[part_two]
-fn part_two(mut grid: Grid<Entry>) -> usize {
-    let height = grid.height();
-
-    let mut found = None;
-    let mut grids: Vec<Grid<Entry>> = vec![];
-    let mut grid_map = HashMap::new();
-    let total_cycles = 1_000_000_000;
-    for i in 0..total_cycles {
-        while move_north(&mut grid) {}
-        while move_west(&mut grid) {}
-        while move_south(&mut grid) {}
-        while move_east(&mut grid) {}
-
-        if let Some(g_i) = grid_map.insert(grid.clone(), grids.len()) {
-            let cycle_length = grids.len() - g_i;
-            let rem = (total_cycles - i - 1) % cycle_length;
-            found = Some(grids[g_i + rem].clone());
-            break;
+fn nth_state<T: Clone + Hash + Eq>(initial: T, mut step: impl FnMut(&mut T), n: usize) -> T {
+    let mut seen = HashMap::new();
+    let mut state = initial;
+    for i in 0..n {
+        if let Some(&cycle_start) = seen.get(&state) {
+            let cycle_length = i - cycle_start;
+            let target = cycle_start + (n - cycle_start) % cycle_length;
+            return seen.into_iter().find(|(_, j)| *j == target).unwrap().0;
         }
-        grids.push(grid.clone());
+        seen.insert(state.clone(), i);
+        step(&mut state);
     }
+    state
+}
 
-    found
-        .unwrap()
-        .rows()
-        .enumerate()
-        .map(|(i, row)| row.iter().filter(|c| **c == Entry::RoundRock).count() * (height - i))
-        .sum()
+#[part_one]
+fn part_one(mut grid: Grid<Entry>) -> usize {
+    while move_rocks(&mut grid, Tilt::North) {}
+    north_load(&grid)
+}
+
+#[part_two]
+fn part_two(grid: Grid<Entry>) -> usize {
+    north_load(&nth_state(grid, spin_cycle, 1_000_000_000))
 }
 
 harness!(part_1: 109661, part_2: 90176);
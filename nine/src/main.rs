@@ -1,34 +1,33 @@
 use advent::prelude::*;
 
-fn extrapolate(input: List<i64, SepBy<Space>>) -> Vec<Vec<i64>> {
-    let mut lines: Vec<Vec<i64>> = vec![];
-    lines.push(input.into());
+fn forward_differences(values: &[i64]) -> Vec<i64> {
+    let mut row = values.to_vec();
+    let mut leading = vec![];
+    while !row.iter().all(|v| *v == 0) {
+        leading.push(row[0]);
+        row = row.windows(2).map(|s| s[1] - s[0]).collect();
+    }
+    leading
+}
 
-    while !matches!(&lines.last(), Some(l) if l.iter().all(|v| *v == 0)) {
-        lines.push(
-            lines
-                .last()
-                .unwrap()
-                .windows(2)
-                .map(|s| s[1] - s[0])
-                .collect(),
-        );
+fn value_at(differences: &[i64], n: i64) -> i64 {
+    let mut binomial = 1;
+    let mut value = 0;
+    for (k, d) in (0..).zip(differences) {
+        value += binomial * d;
+        binomial = binomial * (n - k) / (k + 1);
     }
-    lines
+    value
 }
 
 fn next_in_seq(input: List<i64, SepBy<Space>>) -> i64 {
-    let lines = extrapolate(input);
-    lines.iter().map(|l| l.last().unwrap()).sum()
+    let values: Vec<i64> = input.into();
+    value_at(&forward_differences(&values), values.len() as i64)
 }
 
 fn prev_in_seq(input: List<i64, SepBy<Space>>) -> i64 {
-    let lines = extrapolate(input);
-    lines
-        .iter()
-        .rev()
-        .map(|l| l.first().unwrap())
-        .fold(0, |a, e| e - a)
+    let values: Vec<i64> = input.into();
+    value_at(&forward_differences(&values), -1)
 }
 
 #[part_one]
@@ -17,27 +17,28 @@ fn part_one(input: List<NotWhitespace, TermWith<NewLine>>) -> u64 {
         .sum()
 }
 
-fn find_number(s: &str, reverse: bool) -> u64 {
-    let mut i = if reverse { s.len() - 1 } else { 0 };
+const NUMBER_WORDS: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
 
-    while i < s.len() {
-        if let Ok(n) = s[i..(i + 1)].parse() {
-            return n;
-        }
-        for j in (i + 3)..=s.len() {
-            let substr = &s[i..j];
-            let new = text2num::replace_numbers(substr, &text2num::Language::english(), 0.0);
-            if let Ok(n) = new.parse() {
-                return n;
-            }
-        }
-        if reverse {
-            i = i.wrapping_sub(1);
-        } else {
-            i += 1;
-        }
+fn number_at(s: &str, i: usize) -> Option<u64> {
+    let rest = &s[i..];
+    if let Some(n) = rest.chars().next().and_then(|c| c.to_digit(10)) {
+        return Some(n as u64);
     }
-    panic!("{s:?} has no numbers, reverse={reverse}");
+    NUMBER_WORDS
+        .iter()
+        .position(|w| rest.starts_with(w))
+        .map(|n| n as u64 + 1)
+}
+
+fn find_number(s: &str, reverse: bool) -> u64 {
+    let found = if reverse {
+        (0..s.len()).rev().find_map(|i| number_at(s, i))
+    } else {
+        (0..s.len()).find_map(|i| number_at(s, i))
+    };
+    found.unwrap_or_else(|| panic!("{s:?} has no numbers, reverse={reverse}"))
 }
 
 fn find_numbers(s: &str) -> (u64, u64) {
@@ -1,4 +1,5 @@
 use advent::prelude::*;
+use combine::parser::combinator::from_str;
 
 #[derive(HasParser, Debug)]
 #[parse(sep_by = "\n")]
@@ -9,39 +10,77 @@ struct Input {
     distance: List<u64, StartsWith<Spaces>>,
 }
 
+fn isqrt(n: u128) -> u128 {
+    if n < 2 {
+        return n;
+    }
+    let mut x = 1 << ((128 - n.leading_zeros() + 1) / 2);
+    loop {
+        let y = (x + n / x) / 2;
+        if y >= x {
+            return x;
+        }
+        x = y;
+    }
+}
+
+fn winning_holds(time: u64, distance: u64) -> u64 {
+    let (time, distance) = (time as u128, distance as u128);
+    let beats = |hold: u128| hold * (time - hold) > distance;
+
+    // hold * (time - hold) > distance is a quadratic in hold, symmetric around time / 2
+    let Some(discriminant) = (time * time).checked_sub(4 * distance) else {
+        return 0;
+    };
+    let mut low = (time - isqrt(discriminant)) / 2;
+    while low > 0 && beats(low - 1) {
+        low -= 1;
+    }
+    while low <= time / 2 && !beats(low) {
+        low += 1;
+    }
+    if low > time / 2 {
+        return 0;
+    }
+    (time - 2 * low + 1) as u64
+}
+
 #[part_one]
 fn part_one(input: Input) -> u64 {
     input
         .time
         .iter()
         .zip(input.distance.iter())
-        .map(|(time, distance)| {
-            (0..*time)
-                .filter(|hold| hold * (time - hold) > *distance)
-                .count() as u64
-        })
+        .map(|(time, distance)| winning_holds(*time, *distance))
         .product()
 }
 
+#[derive(Debug)]
+struct SpacedNumber(u64);
+
+impl HasParser for SpacedNumber {
+    #[into_parser]
+    fn parser() -> _ {
+        from_str(
+            many1(satisfy(|c: char| c == ' ' || c.is_ascii_digit()))
+                .map(|s: String| s.replace(' ', "")),
+        )
+        .map(Self)
+    }
+}
+
+#[derive(HasParser, Debug)]
+#[parse(sep_by = "\n")]
+struct SpacedInput {
+    #[parse(before = "Time:")]
+    time: SpacedNumber,
+    #[parse(before = "Distance:")]
+    distance: SpacedNumber,
+}
+
 #[part_two]
-fn part_two(input: Input) -> u64 {
-    let time: u64 = input
-        .time
-        .iter()
-        .map(|n| n.to_string())
-        .collect::<String>()
-        .parse()
-        .unwrap();
-    let distance: u64 = input
-        .distance
-        .iter()
-        .map(|n| n.to_string())
-        .collect::<String>()
-        .parse()
-        .unwrap();
-    (0..time)
-        .filter(|hold| hold * (time - hold) > distance)
-        .count() as u64
+fn part_two(input: SpacedInput) -> u64 {
+    winning_holds(input.time.0, input.distance.0)
 }
 
 harness!(part_1: 771628, part_2: 27363861);
@@ -91,10 +91,6 @@ impl Grid {
         self.data[y][x]
     }
 
-    fn set_square(&mut self, x: usize, y: usize, c: char) {
-        self.data[y][x] = c;
-    }
-
     fn neighbors(&self, x: usize, y: usize) -> Vec<(usize, usize, Direction, char)> {
         let mut pos = vec![];
         for d in Direction::iter() {
@@ -108,87 +104,35 @@ impl Grid {
         pos
     }
 
-    fn walk_til_loop_inner(
-        &self,
-        x: usize,
-        y: usize,
-        prev_d: Direction,
-        seen: &mut HashSet<(usize, usize)>,
-    ) -> Vec<(usize, usize)> {
-        if seen.contains(&(x, y)) {
-            return vec![];
-        }
-        seen.insert((x, y));
-
-        let c = self.square(x, y);
-        let new_dir = walk(prev_d, c).unwrap();
-
-        let (new_x, new_y) = new_dir.new_xy(x, y);
-        let mut path = self.walk_til_loop_inner(new_x, new_y, new_dir, seen);
-        path.push((x, y));
-        path
-    }
-
-    fn walk_til_loop(&self, x: usize, y: usize) -> Vec<(usize, usize)> {
-        let mut seen = HashSet::new();
-        let (nx, ny, d) = self
-            .neighbors(x, y)
+    fn walk_til_loop(&self, start_x: usize, start_y: usize) -> Vec<(usize, usize)> {
+        let (mut x, mut y, mut d) = self
+            .neighbors(start_x, start_y)
             .into_iter()
             .filter_map(|(nx, ny, d, c)| walk(d, c).map(|_| (nx, ny, d)))
             .next()
             .unwrap();
-        seen.insert((x, y));
 
-        let mut path = self.walk_til_loop_inner(nx, ny, d, &mut seen);
-        path.push((x, y));
-        path
-    }
+        let mut path = vec![(start_x, start_y)];
+        while (x, y) != (start_x, start_y) {
+            path.push((x, y));
 
-    fn replace_start(&mut self, x: usize, y: usize) {
-        let mut directions: Vec<_> = self
-            .neighbors(x, y)
-            .into_iter()
-            .filter_map(|(_, _, d, c)| walk(d, c).map(|_| d))
-            .collect();
-        directions.sort();
-
-        use Direction::*;
-        let c = match (directions[0], directions[1]) {
-            (Left, Right) => '-',
-            (Up, Down) => '|',
-            (Left, Down) => '7',
-            (Right, Down) => 'F',
-            (Right, Up) => 'L',
-            (Left, Up) => 'J',
-            _ => panic!(),
-        };
-        self.set_square(x, y, c);
-    }
-
-    fn enclosed(&self, path: &HashSet<(usize, usize)>) -> u64 {
-        let mut enclosed = false;
-        let mut num_enclosed = 0;
-        let mut wall_char = None;
-        for (x, y, c) in self.squares() {
-            if x == 0 {
-                enclosed = false;
-            }
-            if path.contains(&(x, y)) {
-                match (wall_char, c) {
-                    (_, '|') => enclosed = !enclosed,
-                    (_, 'F') | (_, 'L') => wall_char = Some(c),
-                    (Some('F'), 'J') => enclosed = !enclosed,
-                    (Some('L'), '7') => enclosed = !enclosed,
-                    _ => (),
-                }
-            } else if enclosed {
-                num_enclosed += 1;
-            }
+            d = walk(d, self.square(x, y)).unwrap();
+            (x, y) = d.new_xy(x, y);
         }
-        num_enclosed
+        path
     }
 }
 
+fn enclosed(path: &[(usize, usize)]) -> u64 {
+    // shoelace formula gives the loop's area, Pick's theorem the tiles inside it
+    let twice_area: i64 = path
+        .iter()
+        .zip(path.iter().cycle().skip(1))
+        .map(|(&(x1, y1), &(x2, y2))| x1 as i64 * y2 as i64 - x2 as i64 * y1 as i64)
+        .sum();
+    (twice_area.unsigned_abs() + 2 - path.len() as u64) / 2
+}
+
 #[part_one]
 fn part_one(input: List<NotWhitespace, TermWith<NewLine>>) -> u64 {
     let grid = Grid::new(input.into_iter().map(|s| s.0.chars().collect()).collect());
@@ -199,11 +143,9 @@ fn part_one(input: List<NotWhitespace, TermWith<NewLine>>) -> u64 {
 
 #[part_two]
 fn part_two(input: List<NotWhitespace, TermWith<NewLine>>) -> u64 {
-    let mut grid = Grid::new(input.into_iter().map(|s| s.0.chars().collect()).collect());
+    let grid = Grid::new(input.into_iter().map(|s| s.0.chars().collect()).collect());
     let (start_x, start_y, _) = grid.squares().find(|(_, _, c)| *c == 'S').unwrap();
-    grid.replace_start(start_x, start_y);
-    let path: HashSet<_> = grid.walk_til_loop(start_x, start_y).into_iter().collect();
-    grid.enclosed(&path)
+    enclosed(&grid.walk_til_loop(start_x, start_y))
 }
 
 harness!(part_1: 6860, part_2: 343);
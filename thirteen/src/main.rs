@@ -10,7 +10,7 @@ enum RockOrAsh {
 }
 
 fn cmp_sub(
-    row: &[RockOrAsh],
+    row: impl Fn(usize) -> RockOrAsh,
     a_start: usize,
     a_end: usize,
     b_start: usize,
@@ -20,7 +20,7 @@ fn cmp_sub(
     let mut a = a_start;
     let mut dist = 0;
     loop {
-        if row[a] != row[b] {
+        if row(a) != row(b) {
             dist += 1;
         }
         if b == b_end || a == a_end {
@@ -58,13 +58,19 @@ fn hash_map_intersect(a: &mut Option<HashMap<usize, usize>>, b: HashMap<usize, u
     }
 }
 
-fn find_reflections(lines: Vec<Vec<RockOrAsh>>, smudge: bool) -> HashSet<usize> {
+fn find_reflections(
+    num_lines: usize,
+    line_len: usize,
+    square: impl Fn(usize, usize) -> RockOrAsh,
+    smudge: bool,
+) -> HashSet<usize> {
     let mut grid_refs: Option<HashMap<usize, usize>> = None;
-    for line in &lines {
+    for line in 0..num_lines {
         let mut refs_0 = HashSet::new();
         let mut refs_1 = HashSet::new();
-        for i in 0..(line.len() - 1) {
-            match cmp_sub(line, i + 1, min(i * 2 + 1, line.len() - 1), i, 0) {
+        for i in 0..(line_len - 1) {
+            let row = |j| square(line, j);
+            match cmp_sub(row, i + 1, min(i * 2 + 1, line_len - 1), i, 0) {
                 0 => {
                     refs_0.insert(i);
                 }
@@ -88,8 +94,9 @@ fn solve(grids: List<Grid<RockOrAsh>, SepBy<NewLine>>, smudge: bool) -> usize {
     let mut vertical = vec![];
     let mut horizontal = vec![];
     for grid in grids {
-        let v = find_reflections(grid.rows().map(|r| r.to_vec()).collect(), smudge);
-        let h = find_reflections(grid.columns().map(|c| c.to_vec()).collect(), smudge);
+        let (width, height) = (grid.width(), grid.height());
+        let v = find_reflections(height, width, |r, c| grid[r][c], smudge);
+        let h = find_reflections(width, height, |c, r| grid[r][c], smudge);
 
         assert!(!(v.is_empty() && h.is_empty()));
 